@@ -1,20 +1,329 @@
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
 use std::sync::Mutex;
-use tauri::Emitter;
+use std::time::Duration;
+use tauri::{Emitter, Manager};
 use tauri_plugin_shell::ShellExt;
 use tauri_plugin_shell::process::CommandChild;
 
+/// Base delay before the first restart attempt; doubles on each subsequent
+/// attempt up to `MAX_RESTART_DELAY_MS`.
+const BASE_RESTART_DELAY_MS: u64 = 500;
+const MAX_RESTART_DELAY_MS: u64 = 30_000;
+const MAX_RESTART_ATTEMPTS: u32 = 10;
+
+const HEALTH_CHECK_INTERVAL_MS: u64 = 5_000;
+const MAX_CONSECUTIVE_HEALTH_FAILURES: u32 = 3;
+
+const READY_POLL_INTERVAL_MS: u64 = 250;
+const READY_TIMEOUT_MS: u64 = 30_000;
+
+const HTTP_CLIENT_TIMEOUT_SECS: u64 = 30;
+
 pub struct BackendState {
     pub process: Mutex<Option<CommandChild>>,
+    /// Whether the supervisor should auto-restart the backend when it dies
+    /// or stops responding. Toggled by `set_auto_restart`.
+    pub should_restart: AtomicBool,
+    /// Set while `stop_backend` is tearing the process down, so the
+    /// supervisor knows a termination was requested rather than a crash.
+    stopping: AtomicBool,
+    /// Number of restart attempts made since the last successful recovery.
+    restart_attempts: AtomicU32,
+    /// Incremented every time a new sidecar is spawned. Lets a process's own
+    /// monitor task tell whether the `Terminated` event it just saw still
+    /// refers to the process currently stored in `process`, or to a prior
+    /// one that a reconfigure/restart has already superseded.
+    process_generation: AtomicU64,
+    /// Set once `wait_for_ready` succeeds for the current process, cleared
+    /// on stop/terminate/restart. The health watchdog must not probe (let
+    /// alone force-kill) a backend that hasn't reached this yet — otherwise
+    /// it would race `wait_for_ready`'s own startup grace period.
+    is_ready: AtomicBool,
+    /// Most recent startup progress snapshot, so a window opened late can
+    /// render the current stage instead of starting from nothing.
+    pub init_progress: Mutex<Option<InitProgress>>,
+    /// Host/port the sidecar is (or will be) spawned with.
+    pub config: Mutex<BackendConfig>,
+    /// Shared, connection-pooled client used by every backend-facing
+    /// command, so we don't build a fresh `reqwest::Client` per call.
+    pub http_client: reqwest::Client,
 }
 
 impl Default for BackendState {
     fn default() -> Self {
         Self {
             process: Mutex::new(None),
+            should_restart: AtomicBool::new(true),
+            stopping: AtomicBool::new(false),
+            restart_attempts: AtomicU32::new(0),
+            process_generation: AtomicU64::new(0),
+            is_ready: AtomicBool::new(false),
+            init_progress: Mutex::new(None),
+            config: Mutex::new(BackendConfig::default()),
+            http_client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(HTTP_CLIENT_TIMEOUT_SECS))
+                .build()
+                .expect("failed to build backend HTTP client"),
+        }
+    }
+}
+
+/// Host/port the backend sidecar is spawned with, plus any extra CLI args.
+/// Persisted to the app config directory so a chosen port survives restarts.
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+pub struct BackendConfig {
+    pub host: String,
+    pub port: u16,
+    #[serde(default)]
+    pub extra_args: Vec<String>,
+}
+
+impl Default for BackendConfig {
+    fn default() -> Self {
+        Self {
+            host: "127.0.0.1".to_string(),
+            port: 8000,
+            extra_args: Vec::new(),
+        }
+    }
+}
+
+impl BackendConfig {
+    pub(crate) fn base_url(&self) -> String {
+        format!("http://{}:{}", self.host, self.port)
+    }
+}
+
+const CONFIG_FILE_NAME: &str = "backend-config.json";
+
+fn config_file_path(app: &tauri::AppHandle) -> Result<std::path::PathBuf, String> {
+    let dir = app.path().app_config_dir().map_err(|e| e.to_string())?;
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir.join(CONFIG_FILE_NAME))
+}
+
+/// Load the persisted backend config, falling back to defaults if it's
+/// missing or unreadable.
+pub fn load_backend_config(app: &tauri::AppHandle) -> BackendConfig {
+    config_file_path(app)
+        .and_then(|path| std::fs::read_to_string(&path).map_err(|e| e.to_string()))
+        .and_then(|contents| serde_json::from_str(&contents).map_err(|e| e.to_string()))
+        .unwrap_or_default()
+}
+
+fn save_backend_config(app: &tauri::AppHandle, config: &BackendConfig) -> Result<(), String> {
+    let path = config_file_path(app)?;
+    let contents = serde_json::to_string_pretty(config).map_err(|e| e.to_string())?;
+    std::fs::write(path, contents).map_err(|e| e.to_string())
+}
+
+/// Bind-probe for a free port: try the preferred port first, and if it's
+/// taken, ask the OS for any available one instead.
+fn pick_free_port(preferred: u16) -> u16 {
+    if std::net::TcpListener::bind(("127.0.0.1", preferred)).is_ok() {
+        return preferred;
+    }
+
+    std::net::TcpListener::bind(("127.0.0.1", 0))
+        .and_then(|listener| listener.local_addr())
+        .map(|addr| addr.port())
+        .unwrap_or(preferred)
+}
+
+/// Update the proxy's reverse-proxy target to match the current config.
+fn sync_proxy_target(app: &tauri::AppHandle, config: &BackendConfig) {
+    if let Some(proxy) = app.try_state::<std::sync::Arc<crate::proxy::ProxyState>>() {
+        proxy.set_base_url(config.base_url());
+    }
+}
+
+/// Lock a mutex, recovering the guard instead of panicking if a prior holder
+/// panicked while holding it. Used by supervisor tasks (the monitor loop, the
+/// health watchdog) that have no `Result` to surface a poisoned lock through;
+/// `#[tauri::command]` functions should use `.lock().map_err(...)?` instead.
+fn lock_recover<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Change the backend's host/port/extra args, persist it, and restart the
+/// backend so the new configuration takes effect.
+#[tauri::command]
+pub async fn set_backend_config(
+    app: tauri::AppHandle,
+    host: Option<String>,
+    port: Option<u16>,
+    extra_args: Option<Vec<String>>,
+    state: tauri::State<'_, BackendState>,
+) -> Result<BackendConfig, String> {
+    let mut config = state.config.lock().map_err(|e| e.to_string())?.clone();
+    if let Some(host) = host {
+        config.host = host;
+    }
+    if let Some(port) = port {
+        config.port = port;
+    }
+    if let Some(extra_args) = extra_args {
+        config.extra_args = extra_args;
+    }
+
+    save_backend_config(&app, &config)?;
+    *state.config.lock().map_err(|e| e.to_string())? = config.clone();
+    sync_proxy_target(&app, &config);
+    let _ = app.emit("backend-config-changed", config.clone());
+
+    stop_backend(state).await?;
+    let restart_handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let state = restart_handle.state::<BackendState>();
+        if let Err(e) = start_backend(restart_handle.clone(), state).await {
+            log::error!("Failed to restart backend with new config: {}", e);
+        }
+    });
+
+    Ok(config)
+}
+
+/// Startup progress reported to the frontend while the backend extracts
+/// bundled resources and downloads/loads model files.
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+pub struct InitProgress {
+    pub stage: String,
+    pub percent: u8,
+    pub message: String,
+}
+
+/// Raw progress line the sidecar prints to stdout, e.g.
+/// `{"stage":"downloading_model","current":40,"total":100,"message":"..."}`.
+#[derive(serde::Deserialize)]
+struct RawProgress {
+    stage: String,
+    current: u64,
+    total: u64,
+    #[serde(default)]
+    message: String,
+}
+
+/// Parse a stdout line as a structured init-progress update, if it looks
+/// like one. Ordinary log lines fail to parse and are ignored here.
+fn parse_init_progress(line: &str) -> Option<InitProgress> {
+    let raw: RawProgress = serde_json::from_str(line.trim()).ok()?;
+    let percent = if raw.total == 0 {
+        0
+    } else {
+        ((raw.current.min(raw.total) * 100) / raw.total) as u8
+    };
+
+    Some(InitProgress {
+        stage: raw.stage,
+        percent,
+        message: raw.message,
+    })
+}
+
+/// A single log line forwarded to the frontend console.
+#[derive(Clone, serde::Serialize)]
+struct ConsoleEvent {
+    level: String,
+    target: String,
+    timestamp: u64,
+    message: String,
+    /// Where the event originated: "backend" (sidecar stdout/stderr) or "host" (Tauri/Rust side).
+    source: &'static str,
+}
+
+impl ConsoleEvent {
+    fn now_millis() -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0)
+    }
+
+    fn host(level: log::Level, target: &str, message: String) -> Self {
+        Self {
+            level: level.to_string(),
+            target: target.to_string(),
+            timestamp: Self::now_millis(),
+            message,
+            source: "host",
+        }
+    }
+
+    fn backend(level: log::Level, message: String) -> Self {
+        Self {
+            level: level.to_string(),
+            target: "backend".to_string(),
+            timestamp: Self::now_millis(),
+            message,
+            source: "backend",
+        }
+    }
+}
+
+/// `log::Log` implementation that forwards every Rust-side log record at
+/// `Info` level or above to the webview as a `console-log` event, so the
+/// frontend can render a single unified, colored console alongside the
+/// backend's own stdout/stderr. `Debug` and `Trace` records are dropped.
+pub struct FrontendLogger {
+    app: tauri::AppHandle,
+}
+
+impl FrontendLogger {
+    pub fn new(app: tauri::AppHandle) -> Self {
+        Self { app }
+    }
+}
+
+impl log::Log for FrontendLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::LevelFilter::Info
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
         }
+
+        // `FrontendLogger` replaces `tauri_plugin_log` as the global logger
+        // in every build, so keep echoing to the terminal in debug builds
+        // ourselves rather than losing that output.
+        if cfg!(debug_assertions) {
+            eprintln!(
+                "[{}] {}: {}",
+                record.level(),
+                record.target(),
+                record.args()
+            );
+        }
+
+        let event = ConsoleEvent::host(record.level(), record.target(), record.args().to_string());
+        let _ = self.app.emit("console-log", event);
+    }
+
+    fn flush(&self) {}
+}
+
+/// Classify a line of sidecar output into a log level based on common markers.
+fn classify_backend_line(line: &str, is_stderr: bool) -> log::Level {
+    let lower = line.to_ascii_lowercase();
+    if lower.contains("error") || lower.contains("fatal") {
+        log::Level::Error
+    } else if lower.contains("warn") {
+        log::Level::Warn
+    } else if is_stderr {
+        log::Level::Warn
+    } else {
+        log::Level::Info
     }
 }
 
+/// Emit a single backend stdout/stderr line as a `console-log` event.
+fn emit_backend_log(app: &tauri::AppHandle, line: &str, is_stderr: bool) {
+    let level = classify_backend_line(line, is_stderr);
+    let event = ConsoleEvent::backend(level, line.to_string());
+    let _ = app.emit("console-log", event);
+}
+
 #[tauri::command]
 pub async fn start_backend(
     app: tauri::AppHandle,
@@ -28,16 +337,51 @@ pub async fn start_backend(
         }
     }
 
+    // Resolve the host/port to spawn with, picking a free port if the
+    // configured one is already taken, and keep the proxy and persisted
+    // config in sync with whatever we actually use.
+    let mut config = state.config.lock().map_err(|e| e.to_string())?.clone();
+    let available_port = pick_free_port(config.port);
+    if available_port != config.port {
+        log::warn!(
+            "Configured backend port {} is unavailable, using {} instead",
+            config.port,
+            available_port
+        );
+        config.port = available_port;
+        let _ = save_backend_config(&app, &config);
+        let _ = app.emit("backend-config-changed", config.clone());
+    }
+    *state.config.lock().map_err(|e| e.to_string())? = config.clone();
+    sync_proxy_target(&app, &config);
+
+    let mut args = vec![
+        "--host".to_string(),
+        config.host.clone(),
+        "--port".to_string(),
+        config.port.to_string(),
+    ];
+    args.extend(config.extra_args.clone());
+
     // Spawn the sidecar
     let sidecar = app
         .shell()
         .sidecar("localai-backend")
-        .map_err(|e| format!("Failed to create sidecar command: {}", e))?;
+        .map_err(|e| format!("Failed to create sidecar command: {}", e))?
+        .args(args);
 
     let (mut rx, child) = sidecar
         .spawn()
         .map_err(|e| format!("Failed to spawn backend: {}", e))?;
 
+    // Tag this spawn with a generation so its monitor task can tell a stale
+    // `Terminated` event (from a process a reconfigure/restart has already
+    // replaced) apart from one for the process it actually watches.
+    let generation = state.process_generation.fetch_add(1, Ordering::SeqCst) + 1;
+    // This process hasn't passed its first health check yet; the watchdog
+    // must not probe it until `wait_for_ready` says otherwise.
+    state.is_ready.store(false, Ordering::SeqCst);
+
     // Store the child process
     {
         let mut guard = state.process.lock().map_err(|e| e.to_string())?;
@@ -53,13 +397,19 @@ pub async fn start_backend(
             match event {
                 CommandEvent::Stdout(line) => {
                     let text = String::from_utf8_lossy(&line);
-                    log::info!("[Backend] {}", text);
                     let _ = app_handle.emit("backend-log", text.to_string());
+                    emit_backend_log(&app_handle, &text, false);
+
+                    if let Some(progress) = parse_init_progress(&text) {
+                        let state = app_handle.state::<BackendState>();
+                        *lock_recover(&state.init_progress) = Some(progress.clone());
+                        let _ = app_handle.emit("init-progress", progress);
+                    }
                 }
                 CommandEvent::Stderr(line) => {
                     let text = String::from_utf8_lossy(&line);
-                    log::info!("[Backend] {}", text);
                     let _ = app_handle.emit("backend-log", text.to_string());
+                    emit_backend_log(&app_handle, &text, true);
                 }
                 CommandEvent::Error(err) => {
                     log::error!("[Backend Error] {}", err);
@@ -68,38 +418,216 @@ pub async fn start_backend(
                 CommandEvent::Terminated(payload) => {
                     log::info!("[Backend] Process terminated with code: {:?}", payload.code);
                     let _ = app_handle.emit("backend-terminated", payload.code);
+
+                    let state = app_handle.state::<BackendState>();
+                    let was_stopping = state.stopping.swap(false, Ordering::SeqCst);
+
+                    if state.process_generation.load(Ordering::SeqCst) != generation {
+                        // A newer backend has already been spawned (e.g. via
+                        // `set_backend_config`); this event belongs to the
+                        // process it replaced, so leave the current slot and
+                        // restart bookkeeping alone.
+                        log::info!(
+                            "Ignoring stale Terminated event for backend generation {}",
+                            generation
+                        );
+                        continue;
+                    }
+
+                    state.is_ready.store(false, Ordering::SeqCst);
+                    {
+                        let mut guard = lock_recover(&state.process);
+                        *guard = None;
+                    }
+
+                    if !was_stopping && state.should_restart.load(Ordering::SeqCst) {
+                        schedule_restart(app_handle.clone());
+                    }
                 }
                 _ => {}
             }
         }
     });
 
-    // Give the backend a moment to start
-    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+    // Wait until the backend actually answers health checks instead of
+    // blindly sleeping, so "ready" reflects reality even when startup
+    // (resource extraction, model loading) takes longer than expected.
+    wait_for_ready(&app).await?;
+
+    // Any successful start (manual, post-reconfigure, or via the supervisor)
+    // counts as a recovery: forget prior crash attempts so auto-restart
+    // isn't permanently disabled by crashes from long before this start.
+    state.restart_attempts.store(0, Ordering::SeqCst);
 
     Ok("Backend started".to_string())
 }
 
+/// Poll the health endpoint until it succeeds or `READY_TIMEOUT_MS` elapses,
+/// emitting `backend-ready` on success.
+async fn wait_for_ready(app: &tauri::AppHandle) -> Result<(), String> {
+    let deadline = tokio::time::Instant::now() + Duration::from_millis(READY_TIMEOUT_MS);
+
+    loop {
+        let state = app.state::<BackendState>();
+        if check_backend_health(state).await.unwrap_or(false) {
+            app.state::<BackendState>()
+                .is_ready
+                .store(true, Ordering::SeqCst);
+            let _ = app.emit("backend-ready", ());
+            return Ok(());
+        }
+
+        if tokio::time::Instant::now() >= deadline {
+            return Err(format!(
+                "Backend did not become ready within {}ms",
+                READY_TIMEOUT_MS
+            ));
+        }
+
+        tokio::time::sleep(Duration::from_millis(READY_POLL_INTERVAL_MS)).await;
+    }
+}
+
+/// Return the last startup progress snapshot, so a window opened after
+/// startup began can render the current stage immediately.
+#[tauri::command]
+pub fn get_init_progress(
+    state: tauri::State<'_, BackendState>,
+) -> Result<Option<InitProgress>, String> {
+    Ok(state.init_progress.lock().map_err(|e| e.to_string())?.clone())
+}
+
 #[tauri::command]
 pub async fn stop_backend(
     state: tauri::State<'_, BackendState>,
 ) -> Result<String, String> {
+    state.stopping.store(true, Ordering::SeqCst);
+    state.is_ready.store(false, Ordering::SeqCst);
+
     let mut guard = state.process.lock().map_err(|e| e.to_string())?;
 
     if let Some(child) = guard.take() {
         child.kill().map_err(|e| format!("Failed to kill backend: {}", e))?;
         Ok("Backend stopped".to_string())
     } else {
+        state.stopping.store(false, Ordering::SeqCst);
         Ok("Backend was not running".to_string())
     }
 }
 
+/// Toggle whether the supervisor auto-restarts the backend after it dies or
+/// stops responding to health checks.
+#[tauri::command]
+pub fn set_auto_restart(
+    enabled: bool,
+    state: tauri::State<'_, BackendState>,
+) -> Result<(), String> {
+    state.should_restart.store(enabled, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Schedule a restart of the backend with exponential backoff, giving up
+/// after `MAX_RESTART_ATTEMPTS` consecutive failures.
+fn schedule_restart(app: tauri::AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let state = app.state::<BackendState>();
+
+        let attempt = state.restart_attempts.fetch_add(1, Ordering::SeqCst) + 1;
+        if attempt > MAX_RESTART_ATTEMPTS {
+            log::error!(
+                "Backend restart giving up after {} attempts",
+                MAX_RESTART_ATTEMPTS
+            );
+            let _ = app.emit("backend-restart-failed", attempt);
+            return;
+        }
+
+        let delay_ms = (BASE_RESTART_DELAY_MS.saturating_mul(1 << (attempt - 1)))
+            .min(MAX_RESTART_DELAY_MS);
+
+        log::warn!(
+            "Backend terminated unexpectedly, restarting in {}ms (attempt {}/{})",
+            delay_ms,
+            attempt,
+            MAX_RESTART_ATTEMPTS
+        );
+        let _ = app.emit(
+            "backend-restarting",
+            serde_json::json!({ "attempt": attempt, "delay_ms": delay_ms }),
+        );
+
+        tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+
+        let state = app.state::<BackendState>();
+        match start_backend(app.clone(), state).await {
+            Ok(_) => {
+                // `start_backend` itself resets `restart_attempts` on success.
+                log::info!("Backend recovered after {} attempt(s)", attempt);
+                let _ = app.emit("backend-recovered", ());
+            }
+            Err(e) => {
+                log::error!("Restart attempt {} failed: {}", attempt, e);
+            }
+        }
+    });
+}
+
+/// Background loop that periodically probes `/api/health` and restarts the
+/// backend if the process is alive but keeps failing to respond.
+pub async fn run_health_watchdog(app: tauri::AppHandle) {
+    let mut consecutive_failures: u32 = 0;
+
+    loop {
+        tokio::time::sleep(Duration::from_millis(HEALTH_CHECK_INTERVAL_MS)).await;
+
+        let state = app.state::<BackendState>();
+        if !state.should_restart.load(Ordering::SeqCst) {
+            consecutive_failures = 0;
+            continue;
+        }
+
+        let has_process = lock_recover(&state.process).is_some();
+        // Don't probe (or count failures for) a backend that hasn't passed
+        // its first health check yet — it may simply still be inside
+        // `wait_for_ready`'s own startup grace period.
+        if !has_process || !state.is_ready.load(Ordering::SeqCst) {
+            consecutive_failures = 0;
+            continue;
+        }
+
+        match check_backend_health(state).await {
+            Ok(true) => consecutive_failures = 0,
+            _ => {
+                consecutive_failures += 1;
+                log::warn!(
+                    "Backend health check failed ({}/{})",
+                    consecutive_failures,
+                    MAX_CONSECUTIVE_HEALTH_FAILURES
+                );
+
+                if consecutive_failures >= MAX_CONSECUTIVE_HEALTH_FAILURES {
+                    consecutive_failures = 0;
+                    log::warn!("Backend unresponsive, forcing restart");
+
+                    let child = lock_recover(&state.process).take();
+                    if let Some(child) = child {
+                        // Killing the process fires `CommandEvent::Terminated`,
+                        // which schedules the actual restart.
+                        let _ = child.kill();
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[tauri::command]
-pub async fn check_backend_health() -> Result<bool, String> {
-    let client = reqwest::Client::new();
+pub async fn check_backend_health(state: tauri::State<'_, BackendState>) -> Result<bool, String> {
+    let base_url = state.config.lock().map_err(|e| e.to_string())?.base_url();
 
-    match client
-        .get("http://localhost:8000/api/health")
+    match state
+        .http_client
+        .get(format!("{}/api/health", base_url))
         .timeout(std::time::Duration::from_secs(2))
         .send()
         .await
@@ -109,6 +637,74 @@ pub async fn check_backend_health() -> Result<bool, String> {
     }
 }
 
+/// Response shape returned by `backend_request`, mirroring a plain HTTP
+/// response so the frontend doesn't need endpoint-specific Rust commands.
+#[derive(serde::Serialize)]
+pub struct ProxyResponse {
+    pub status: u16,
+    pub headers: std::collections::HashMap<String, String>,
+    pub body: serde_json::Value,
+}
+
+/// Generic, typed proxy for hitting any backend endpoint (chat, models,
+/// embeddings, ...) through the shared, connection-pooled HTTP client
+/// instead of adding a new `#[tauri::command]` per endpoint.
+#[tauri::command]
+pub async fn backend_request(
+    method: String,
+    path: String,
+    body: Option<serde_json::Value>,
+    headers: Option<std::collections::HashMap<String, String>>,
+    state: tauri::State<'_, BackendState>,
+) -> Result<ProxyResponse, String> {
+    let base_url = state.config.lock().map_err(|e| e.to_string())?.base_url();
+    let url = format!("{}{}", base_url, path);
+
+    let method = method
+        .parse::<reqwest::Method>()
+        .map_err(|e| format!("Invalid HTTP method '{}': {}", method, e))?;
+
+    let mut request = state.http_client.request(method, &url);
+    if let Some(headers) = headers {
+        for (name, value) in headers {
+            request = request.header(name, value);
+        }
+    }
+    if let Some(body) = body {
+        request = request.json(&body);
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| format!("Backend request failed: {}", e))?;
+
+    let status = response.status().as_u16();
+    let response_headers = response
+        .headers()
+        .iter()
+        .filter_map(|(name, value)| {
+            value
+                .to_str()
+                .ok()
+                .map(|v| (name.to_string(), v.to_string()))
+        })
+        .collect();
+
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|e| format!("Failed to read backend response: {}", e))?;
+    let body = serde_json::from_slice(&bytes)
+        .unwrap_or_else(|_| serde_json::Value::String(String::from_utf8_lossy(&bytes).to_string()));
+
+    Ok(ProxyResponse {
+        status,
+        headers: response_headers,
+        body,
+    })
+}
+
 #[tauri::command]
 pub fn get_backend_status(
     state: tauri::State<'_, BackendState>,
@@ -116,3 +712,94 @@ pub fn get_backend_status(
     let guard = state.process.lock().map_err(|e| e.to_string())?;
     Ok(guard.is_some())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_init_progress_computes_percent() {
+        let progress =
+            parse_init_progress(r#"{"stage":"downloading_model","current":40,"total":100,"message":"hi"}"#)
+                .expect("valid progress line should parse");
+        assert_eq!(progress.stage, "downloading_model");
+        assert_eq!(progress.percent, 40);
+        assert_eq!(progress.message, "hi");
+    }
+
+    #[test]
+    fn parse_init_progress_treats_zero_total_as_zero_percent() {
+        let progress = parse_init_progress(r#"{"stage":"starting","current":0,"total":0}"#)
+            .expect("total of zero should still parse");
+        assert_eq!(progress.percent, 0);
+    }
+
+    #[test]
+    fn parse_init_progress_clamps_current_past_total() {
+        let progress = parse_init_progress(r#"{"stage":"done","current":150,"total":100}"#)
+            .expect("over-count should still parse");
+        assert_eq!(progress.percent, 100);
+    }
+
+    #[test]
+    fn parse_init_progress_ignores_non_progress_lines() {
+        assert!(parse_init_progress("just a regular log line").is_none());
+        assert!(parse_init_progress(r#"{"unrelated":"json"}"#).is_none());
+    }
+
+    #[test]
+    fn pick_free_port_keeps_preferred_port_when_available() {
+        // Bind to a random port first so it's guaranteed free, then ask for it.
+        let listener = std::net::TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+        drop(listener);
+
+        assert_eq!(pick_free_port(port), port);
+    }
+
+    #[test]
+    fn pick_free_port_falls_back_when_preferred_port_is_taken() {
+        let listener = std::net::TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let taken_port = listener.local_addr().unwrap().port();
+
+        let picked = pick_free_port(taken_port);
+        assert_ne!(picked, taken_port);
+        assert_ne!(picked, 0);
+    }
+
+    #[test]
+    fn classify_backend_line_flags_error_keywords() {
+        assert_eq!(
+            classify_backend_line("FATAL: out of memory", false),
+            log::Level::Error
+        );
+        assert_eq!(
+            classify_backend_line("something errored", false),
+            log::Level::Error
+        );
+    }
+
+    #[test]
+    fn classify_backend_line_flags_warn_keyword() {
+        assert_eq!(
+            classify_backend_line("warning: deprecated flag", false),
+            log::Level::Warn
+        );
+    }
+
+    #[test]
+    fn classify_backend_line_treats_plain_stderr_as_warn() {
+        assert_eq!(
+            classify_backend_line("backend listening on port 8000", true),
+            log::Level::Warn
+        );
+    }
+
+    #[test]
+    fn classify_backend_line_treats_plain_stdout_as_info() {
+        assert_eq!(
+            classify_backend_line("backend listening on port 8000", false),
+            log::Level::Info
+        );
+    }
+}
@@ -1,32 +1,72 @@
 mod commands;
+mod proxy;
 
-use commands::{start_backend, stop_backend, check_backend_health, get_backend_status, BackendState};
+use commands::{
+    start_backend, stop_backend, check_backend_health, get_backend_status, set_auto_restart,
+    run_health_watchdog, get_init_progress, set_backend_config, load_backend_config,
+    backend_request, BackendConfig, BackendState, FrontendLogger,
+};
+use proxy::ProxyState;
+use std::sync::Arc;
 use tauri::{Manager, Emitter};
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    // Built once up front so the proxy reverse-proxies over the same
+    // connection-pooled, timeout-configured client every other
+    // backend-facing command uses, rather than a client of its own.
+    let backend_state = BackendState::default();
+    let http_client = backend_state.http_client.clone();
+
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_process::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_os::init())
-        .manage(BackendState::default())
+        .manage(backend_state)
+        .manage(Arc::new(ProxyState::new(
+            http_client,
+            BackendConfig::default().base_url(),
+        )))
+        .register_asynchronous_uri_scheme_protocol(proxy::SCHEME, |ctx, request, responder| {
+            let state = ctx.app_handle().state::<Arc<ProxyState>>().inner().clone();
+            tauri::async_runtime::spawn(async move {
+                let response = proxy::handle_request(state, request).await;
+                responder.respond(response);
+            });
+        })
         .invoke_handler(tauri::generate_handler![
             start_backend,
             stop_backend,
             check_backend_health,
-            get_backend_status
+            get_backend_status,
+            set_auto_restart,
+            get_init_progress,
+            set_backend_config,
+            backend_request
         ])
         .setup(|app| {
-            // Setup logging in debug mode
-            if cfg!(debug_assertions) {
-                app.handle().plugin(
-                    tauri_plugin_log::Builder::default()
-                        .level(log::LevelFilter::Info)
-                        .build(),
-                )?;
+            // Route every Rust-side log record at `Info` level or above (host
+            // errors, restart decisions, health-check failures) to the
+            // webview so backend and host logs
+            // render in a single, unified console, in every build
+            // configuration. This replaces `tauri_plugin_log` outright since
+            // `log::set_boxed_logger` can only claim the global slot once;
+            // `FrontendLogger` echoes to the terminal itself in debug builds
+            // so we don't lose that output.
+            let frontend_logger = FrontendLogger::new(app.handle().clone());
+            log::set_boxed_logger(Box::new(frontend_logger))
+                .map(|()| log::set_max_level(log::LevelFilter::Info))
+                .expect("failed to install frontend logger");
+
+            // Load the persisted host/port config (if any) before the backend
+            // or the proxy need it.
+            let loaded_config = load_backend_config(app.handle());
+            if let Some(proxy) = app.try_state::<Arc<ProxyState>>() {
+                proxy.set_base_url(loaded_config.base_url());
             }
+            *app.state::<BackendState>().config.lock().unwrap() = loaded_config;
 
             // Auto-start the backend
             let app_handle = app.handle().clone();
@@ -48,6 +88,11 @@ pub fn run() {
                 }
             });
 
+            // Watch backend health in the background and force a restart if
+            // it stops responding while still alive.
+            let watchdog_handle = app.handle().clone();
+            tauri::async_runtime::spawn(run_health_watchdog(watchdog_handle));
+
             Ok(())
         })
         .on_window_event(|window, event| {
@@ -0,0 +1,169 @@
+//! In-process bridge between the webview and the backend sidecar.
+//!
+//! Instead of the frontend talking to `http://localhost:8000` directly (which
+//! ties us to a fixed port and forces CORS configuration on the sidecar), we
+//! register a custom `localai://` URI scheme. Every request the webview makes
+//! against that scheme is converted into an `axum::Router` call, which
+//! reverse-proxies it to the sidecar over the shared `reqwest::Client`. This
+//! also gives us one place to inject auth headers and normalize backend
+//! errors before they reach the frontend.
+
+use axum::body::Body;
+use axum::extract::Request as AxumRequest;
+use axum::response::Response as AxumResponse;
+use axum::routing::any;
+use axum::Router;
+use http_body_util::BodyExt;
+use std::sync::{Arc, RwLock};
+use tower::{Service, ServiceExt};
+
+pub const SCHEME: &str = "localai";
+
+/// Holds the Axum router used to reverse-proxy `localai://` requests to the
+/// backend. Wrapped in a `tokio::Mutex` because `tower::Service::call` needs
+/// `&mut self` and the router is shared across concurrent protocol requests.
+pub struct ProxyState {
+    router: tokio::sync::Mutex<Router>,
+    /// Current reverse-proxy target, e.g. `http://127.0.0.1:8000`. Held
+    /// behind a lock of its own (rather than rebuilding the router) so
+    /// `set_base_url` can update it whenever the backend config changes.
+    backend_base_url: Arc<RwLock<String>>,
+}
+
+impl ProxyState {
+    pub fn new(client: reqwest::Client, backend_base_url: String) -> Self {
+        let backend_base_url = Arc::new(RwLock::new(backend_base_url));
+        Self {
+            router: tokio::sync::Mutex::new(build_router(client, backend_base_url.clone())),
+            backend_base_url,
+        }
+    }
+
+    /// Point the proxy at a new backend base URL, e.g. after the configured
+    /// host/port changes.
+    pub fn set_base_url(&self, new_base_url: String) {
+        *self.backend_base_url.write().unwrap() = new_base_url;
+    }
+}
+
+fn build_router(client: reqwest::Client, backend_base_url: Arc<RwLock<String>>) -> Router {
+    let client = Arc::new(client);
+
+    Router::new().fallback(any(move |req: AxumRequest| {
+        let client = client.clone();
+        let backend_base_url = backend_base_url.clone();
+        async move { proxy_to_backend(client, backend_base_url, req).await }
+    }))
+}
+
+async fn proxy_to_backend(
+    client: Arc<reqwest::Client>,
+    backend_base_url: Arc<RwLock<String>>,
+    req: AxumRequest,
+) -> AxumResponse {
+    let (parts, body) = req.into_parts();
+
+    let body_bytes = match body.collect().await {
+        Ok(collected) => collected.to_bytes(),
+        Err(e) => {
+            return error_response(
+                axum::http::StatusCode::BAD_GATEWAY,
+                format!("Failed to read request body: {}", e),
+            );
+        }
+    };
+
+    let path_and_query = parts
+        .uri
+        .path_and_query()
+        .map(|pq| pq.as_str())
+        .unwrap_or("/");
+    let base_url = backend_base_url.read().unwrap().clone();
+    let url = format!("{}{}", base_url, path_and_query);
+
+    let mut req_builder = client
+        .request(parts.method.clone(), &url)
+        .body(body_bytes.to_vec());
+
+    for (name, value) in parts.headers.iter() {
+        if name == axum::http::header::HOST {
+            continue;
+        }
+        req_builder = req_builder.header(name, value);
+    }
+
+    match req_builder.send().await {
+        Ok(resp) => convert_reqwest_response(resp).await,
+        Err(e) => error_response(
+            axum::http::StatusCode::BAD_GATEWAY,
+            format!("Backend request failed: {}", e),
+        ),
+    }
+}
+
+async fn convert_reqwest_response(resp: reqwest::Response) -> AxumResponse {
+    let status = resp.status();
+    let headers = resp.headers().clone();
+
+    let body_bytes = match resp.bytes().await {
+        Ok(b) => b,
+        Err(e) => {
+            return error_response(
+                axum::http::StatusCode::BAD_GATEWAY,
+                format!("Failed to read backend response: {}", e),
+            );
+        }
+    };
+
+    let mut builder = AxumResponse::builder().status(status);
+    for (name, value) in headers.iter() {
+        builder = builder.header(name, value);
+    }
+
+    builder.body(Body::from(body_bytes)).unwrap_or_else(|_| {
+        error_response(
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            "Failed to build proxied response".to_string(),
+        )
+    })
+}
+
+fn error_response(status: axum::http::StatusCode, message: String) -> AxumResponse {
+    AxumResponse::builder()
+        .status(status)
+        .header(axum::http::header::CONTENT_TYPE, "text/plain")
+        .body(Body::from(message))
+        .expect("error response is always well-formed")
+}
+
+/// Convert a Tauri custom-protocol request into an Axum request, route it
+/// through the shared router, and convert the result back into the response
+/// type Tauri expects.
+pub async fn handle_request(
+    state: Arc<ProxyState>,
+    request: tauri::http::Request<Vec<u8>>,
+) -> tauri::http::Response<Vec<u8>> {
+    let (parts, body) = request.into_parts();
+    let axum_request = AxumRequest::from_parts(parts, Body::from(body));
+
+    let mut router = state.router.lock().await.clone();
+    let response = match router.ready().await {
+        Ok(router) => router
+            .call(axum_request)
+            .await
+            .expect("Router::call is infallible"),
+        Err(_) => error_response(
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            "Proxy router unavailable".to_string(),
+        ),
+    };
+
+    let (parts, body) = response.into_parts();
+    let bytes = body
+        .collect()
+        .await
+        .map(|collected| collected.to_bytes())
+        .unwrap_or_default();
+
+    tauri::http::Response::from_parts(parts, bytes.to_vec())
+}